@@ -6,7 +6,7 @@
 )]
 #![doc = include_str!("../README.md")]
 
-use std::{marker::PhantomData, ops::Range};
+use std::{backtrace::Backtrace, ops::Range, sync::Arc};
 
 /// All the methods to render an [`UserFacingError`]
 pub mod render;
@@ -19,12 +19,40 @@ pub trait AsUserFacingError {
 
 /// Internal Information for generating [`UserFacingError`]s
 pub struct UFEContext {
-    _private: PhantomData<()>,
+    backtrace: Option<Arc<Backtrace>>,
+}
+
+impl UFEContext {
+    /// Create a new context, capturing a [`Backtrace`] if enabled via `RUST_LIB_BACKTRACE` or
+    /// `RUST_BACKTRACE`, mirroring `anyhow`'s capture behavior.
+    pub fn new() -> Self {
+        let backtrace = Backtrace::capture();
+        UFEContext {
+            backtrace: (backtrace.status() == std::backtrace::BacktraceStatus::Captured)
+                .then(|| Arc::new(backtrace)),
+        }
+    }
+
+    /// The backtrace captured when this context was created, if backtrace capture was enabled.
+    ///
+    /// Cheap to call repeatedly: the backtrace is reference-counted so converters can attach it
+    /// to more than one [`ErrorCause`] via [`ErrorCause::with_backtrace`].
+    pub fn backtrace(&self) -> Option<Arc<Backtrace>> {
+        self.backtrace.clone()
+    }
+}
+
+impl Default for UFEContext {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl std::fmt::Debug for UFEContext {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("UFEContext").finish_non_exhaustive()
+        f.debug_struct("UFEContext")
+            .field("backtrace_captured", &self.backtrace.is_some())
+            .finish_non_exhaustive()
     }
 }
 
@@ -117,6 +145,7 @@ pub static UFE_SUPPORTED: [UFEConverter] = [..];
 ///
 /// This approach is more work, but leads to more informative errors for end users.
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct UserFacingError {
     /// The cause of the error
     pub error: ErrorCause,
@@ -124,16 +153,34 @@ pub struct UserFacingError {
     pub related: Vec<UserFacingError>,
 }
 
+impl UserFacingError {
+    /// Wrap this error as the underlying cause of a higher-level one, anyhow's `.context(...)`
+    /// style.
+    ///
+    /// The returned [`UserFacingError`] uses `summary` as its own [`ErrorCause::summary`] and
+    /// keeps `self` as the first entry of its [`UserFacingError::related`] chain, so the original
+    /// error is still reachable (and rendered) as the "how it occurred" layer.
+    pub fn context(self, summary: impl Into<String>) -> UserFacingError {
+        UserFacingError {
+            error: ErrorCause::default().summary(summary),
+            related: vec![self],
+        }
+    }
+}
+
 /// A label in a piece of text, shown to the user
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct FileLabel {
     /// The byte-indexed slice where this label gets applied to
+    #[cfg_attr(feature = "serde", serde(with = "range_as_bounds"))]
     pub range: Range<usize>,
     /// The message shown to the user
     pub message: String,
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 /// A file and labels that highlight parts of it
 pub struct FileHighlight {
     /// The path where the file was found
@@ -144,9 +191,31 @@ pub struct FileHighlight {
     pub labels: Vec<FileLabel>,
 }
 
-#[derive(Debug, Default, derive_setters::Setters)]
+#[cfg(feature = "serde")]
+mod range_as_bounds {
+    use std::ops::Range;
+
+    use serde::{Serialize, Serializer};
+
+    #[derive(Serialize)]
+    struct RangeAsBounds {
+        start: usize,
+        end: usize,
+    }
+
+    pub(crate) fn serialize<S: Serializer>(range: &Range<usize>, serializer: S) -> Result<S::Ok, S::Error> {
+        RangeAsBounds {
+            start: range.start,
+            end: range.end,
+        }
+        .serialize(serializer)
+    }
+}
+
+#[derive(Default, derive_setters::Setters)]
 #[setters(strip_option)]
 #[non_exhaustive]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 /// The cause of an error
 ///
 /// # Note
@@ -165,6 +234,80 @@ pub struct ErrorCause {
     pub extended_reason: Option<String>,
     /// If one or multiple files are associated to this error, mark them here.
     pub file_highlights: Vec<FileHighlight>,
+    /// Concrete, actionable steps the user can take to resolve the error, rendered as a bulleted
+    /// "How to fix" section. Keep each entry to a single step.
+    pub suggestions: Vec<String>,
+    /// The process exit code that should be used when this error terminates a `main` function.
+    ///
+    /// Follows the "0 = ok, 1 = minor, 2 = major" convention popularized by uutils. When unset,
+    /// [`render::to_exit_code`] uses the first code set in the related chain, and defaults to `1`
+    /// when none is set anywhere.
+    pub exit_code: Option<i32>,
+    /// Arbitrary typed values attached to this error, for converters to thread structured,
+    /// machine-usable context (an HTTP status, a suggested command, ...) without inventing a new
+    /// field for every use case. Use [`ErrorCause::attach`] to add one and
+    /// [`ErrorCause::downcast_attachment`] to retrieve it.
+    #[setters(skip)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub attachments: Vec<Box<dyn std::any::Any + Send + Sync>>,
+    /// Printable attachments, rendered beneath the extended reason. Use
+    /// [`ErrorCause::attach_printable`] to add one.
+    #[setters(skip)]
+    pub printable_attachments: Vec<String>,
+    /// The backtrace captured when this error was generated, if backtrace capture was enabled
+    /// for the [`UFEContext`] it was built from. See [`ErrorCause::with_backtrace`].
+    #[setters(skip)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub backtrace: Option<Arc<Backtrace>>,
+}
+
+impl std::fmt::Debug for ErrorCause {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ErrorCause")
+            .field("summary", &self.summary)
+            .field("extended_reason", &self.extended_reason)
+            .field("file_highlights", &self.file_highlights)
+            .field("suggestions", &self.suggestions)
+            .field("exit_code", &self.exit_code)
+            .field("attachments", &format_args!("[{} attachment(s)]", self.attachments.len()))
+            .field("printable_attachments", &self.printable_attachments)
+            .field("backtrace", &self.backtrace.is_some())
+            .finish_non_exhaustive()
+    }
+}
+
+impl ErrorCause {
+    /// Attach an arbitrary typed value to this error cause.
+    ///
+    /// Renderers and application code can later retrieve it with
+    /// [`ErrorCause::downcast_attachment`].
+    pub fn attach<T: Send + Sync + 'static>(mut self, value: T) -> Self {
+        self.attachments.push(Box::new(value));
+        self
+    }
+
+    /// Attach a value that should be printed beneath the extended reason.
+    pub fn attach_printable(mut self, value: impl std::fmt::Display) -> Self {
+        self.printable_attachments.push(value.to_string());
+        self
+    }
+
+    /// Find the first attached value of type `T`, if any was attached via [`ErrorCause::attach`].
+    pub fn downcast_attachment<T: 'static>(&self) -> Option<&T> {
+        self.attachments
+            .iter()
+            .find_map(|a| a.downcast_ref::<T>())
+    }
+
+    /// Attach the backtrace captured in `ctx`, if backtrace capture was enabled.
+    ///
+    /// This is a no-op when `ctx` did not capture a backtrace, so it is always safe to call.
+    pub fn with_backtrace(mut self, ctx: &UFEContext) -> Self {
+        if let Some(backtrace) = ctx.backtrace() {
+            self.backtrace = Some(backtrace);
+        }
+        self
+    }
 }
 
 /// A helper struct to turn any reference to an [`std::error::Error`] into either the best
@@ -189,6 +332,27 @@ impl<'a> PotentiallyUnclearError<&'a (dyn std::error::Error + 'static)> {
     pub fn from_error<E: std::error::Error + 'static>(e: &'a E) -> Self {
         Self(e as &dyn std::error::Error)
     }
+
+    /// Convert this error and immediately wrap it with a higher-level context message.
+    ///
+    /// Equivalent to calling [`AsUserFacingError::as_user_facing_error`] followed by
+    /// [`UserFacingError::context`], so application code can annotate a raw
+    /// [`std::error::Error`] at each `?` site:
+    ///
+    /// ```
+    /// # use ufe::{PotentiallyUnclearError, UFEContext};
+    /// # #[derive(Debug, thiserror::Error)]
+    /// # #[error("no such file or directory")]
+    /// # struct IoError;
+    /// let ctx = UFEContext::new();
+    /// let e = IoError;
+    /// let error = PotentiallyUnclearError::from_error(&e)
+    ///     .context(&ctx, "Could not load the configuration");
+    /// assert_eq!(error.error.summary, "Could not load the configuration");
+    /// ```
+    pub fn context(&self, ctx: &UFEContext, summary: impl Into<String>) -> UserFacingError {
+        self.as_user_facing_error(ctx).context(summary)
+    }
 }
 
 impl AsUserFacingError for PotentiallyUnclearError<&(dyn std::error::Error + 'static)> {
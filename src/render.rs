@@ -1,38 +1,153 @@
 use std::fmt::Write;
 
-use ariadne::{ColorGenerator, FnCache, Label, Report};
+use ariadne::{ColorGenerator, Fmt, FnCache, Label, Report};
 
 use super::UserFacingError;
 
+/// The number of columns each level of `related`-error nesting is indented by.
+const INDENT_WIDTH: usize = 2;
+
 struct Context {
-    _max_width: usize,
+    max_width: usize,
     first_run: bool,
+    depth: usize,
+    show_technical_details: bool,
 }
 
 impl Context {
     fn go_to_inner(&self) -> Context {
         Context {
             first_run: false,
+            depth: self.depth + 1,
             ..*self
         }
     }
+
+    fn indent(&self) -> String {
+        " ".repeat(self.depth * INDENT_WIDTH)
+    }
+}
+
+/// Word-wrap `text` to `width` columns, prefixing every line (including the first) with `indent`.
+///
+/// Existing newlines in `text` are preserved as paragraph breaks; wrapping only happens at
+/// whitespace within a paragraph.
+fn wrap_text(text: &str, width: usize, indent: &str) -> String {
+    let available = width.saturating_sub(indent.chars().count()).max(1);
+    let mut output = String::new();
+
+    for (i, line) in text.split('\n').enumerate() {
+        if i > 0 {
+            output.push('\n');
+        }
+
+        output.push_str(indent);
+        let mut line_len = 0;
+        let mut at_line_start = true;
+
+        for word in line.split_whitespace() {
+            let word_len = word.chars().count();
+            if !at_line_start && line_len + 1 + word_len > available {
+                output.push('\n');
+                output.push_str(indent);
+                line_len = 0;
+                at_line_start = true;
+            }
+
+            if !at_line_start {
+                output.push(' ');
+                line_len += 1;
+            }
+            output.push_str(word);
+            line_len += word_len;
+            at_line_start = false;
+        }
+    }
+
+    output
 }
 
 /// Render a chain of errors to the user, meant to be displayed on the terminal
+///
+/// This is the clean, end-user-facing output: it never includes captured backtraces, even if
+/// [`UFEContext`](crate::UFEContext) capture was enabled (e.g. via `RUST_BACKTRACE=1` in
+/// production). Use [`render_for_terminal_with_technical_details`] when rendering for developers.
 pub fn render_for_terminal(error: &UserFacingError, max_width: usize) -> String {
     let context = Context {
-        _max_width: max_width,
+        max_width,
+        first_run: true,
+        depth: 0,
+        show_technical_details: false,
+    };
+    render_for_terminal_inner(error, &context)
+}
+
+/// Render a chain of errors like [`render_for_terminal`], additionally appending a dimmed
+/// "Technical details" block with the captured backtrace for every error in the chain that has
+/// one.
+///
+/// This is an explicit opt-in for developer-facing output (e.g. a `--verbose` CLI flag or a debug
+/// log sink) and should not be wired up to the default end-user path.
+pub fn render_for_terminal_with_technical_details(
+    error: &UserFacingError,
+    max_width: usize,
+) -> String {
+    let context = Context {
+        max_width,
         first_run: true,
+        depth: 0,
+        show_technical_details: true,
     };
     render_for_terminal_inner(error, &context)
 }
 
 fn render_for_terminal_inner(error: &UserFacingError, context: &Context) -> String {
     let mut output = String::new();
-    writeln!(&mut output, "{}", &error.error.summary).unwrap();
+    let indent = context.indent();
+    let body_indent = format!("{indent}  ");
+    let mut section_colors = ColorGenerator::new();
+
+    writeln!(&mut output, "{}{}", indent, "Summary".fg(section_colors.next())).unwrap();
+    writeln!(
+        &mut output,
+        "{}",
+        wrap_text(&error.error.summary, context.max_width, &body_indent)
+    )
+    .unwrap();
+
+    if error.error.extended_reason.is_some() || !error.error.printable_attachments.is_empty() {
+        writeln!(&mut output, "\n{}{}", indent, "Reason".fg(section_colors.next())).unwrap();
+
+        if let Some(extended) = &error.error.extended_reason {
+            writeln!(
+                &mut output,
+                "{}",
+                wrap_text(extended, context.max_width, &body_indent)
+            )
+            .unwrap();
+        }
+
+        for attachment in &error.error.printable_attachments {
+            writeln!(
+                &mut output,
+                "{}",
+                wrap_text(attachment, context.max_width, &body_indent)
+            )
+            .unwrap();
+        }
+    }
 
-    if let Some(extended) = &error.error.extended_reason {
-        writeln!(&mut output, "\n{}", extended).unwrap();
+    if !error.error.suggestions.is_empty() {
+        writeln!(&mut output, "\n{}{}", indent, "How to fix".fg(section_colors.next())).unwrap();
+
+        for suggestion in &error.error.suggestions {
+            writeln!(
+                &mut output,
+                "{}",
+                wrap_text(&format!("- {suggestion}"), context.max_width, &body_indent)
+            )
+            .unwrap();
+        }
     }
 
     for fh in &error.error.file_highlights {
@@ -58,7 +173,7 @@ fn render_for_terminal_inner(error: &UserFacingError, context: &Context) -> Stri
 
     if !error.related.is_empty() {
         if context.first_run {
-            writeln!(&mut output, "Detailed informations:").unwrap();
+            writeln!(&mut output, "{}Detailed informations:", indent).unwrap();
         }
 
         for err in &error.related {
@@ -72,5 +187,104 @@ fn render_for_terminal_inner(error: &UserFacingError, context: &Context) -> Stri
         }
     }
 
+    if context.show_technical_details {
+        if let Some(backtrace) = &error.error.backtrace {
+            let dim = ariadne::Color::Fixed(8);
+            writeln!(&mut output, "\n{indent}{}", "Technical details".fg(dim)).unwrap();
+            writeln!(
+                &mut output,
+                "{}",
+                wrap_text(&backtrace.to_string(), context.max_width, &body_indent).fg(dim)
+            )
+            .unwrap();
+        }
+    }
+
     output
 }
+
+/// Resolve the effective process exit code for an error chain.
+///
+/// Walks `error` and its [`UserFacingError::related`] chain, root first, and returns the first
+/// explicit [`crate::ErrorCause::exit_code`] found. Defaults to `1` when none is set, matching the
+/// "0 = ok, 1 = minor, 2 = major" convention popularized by uutils.
+pub fn to_exit_code(error: &UserFacingError) -> i32 {
+    fn find(error: &UserFacingError) -> Option<i32> {
+        error
+            .error
+            .exit_code
+            .or_else(|| error.related.iter().find_map(find))
+    }
+
+    find(error).unwrap_or(1)
+}
+
+/// Render `error` for the terminal, print it to stderr, then terminate the process with its
+/// resolved exit code.
+///
+/// See [`to_exit_code`] for how the code is resolved.
+pub fn exit_with(error: &UserFacingError) -> ! {
+    eprintln!("{}", render_for_terminal(error, 100));
+    std::process::exit(to_exit_code(error));
+}
+
+/// Render `error` as a JSON string, for consumption by tooling such as CI annotators or
+/// editor/LSP-style consumers that need structured error data rather than pretty terminal text.
+#[cfg(feature = "serde")]
+pub fn render_as_json(error: &UserFacingError) -> String {
+    serde_json::to_string(error).expect("UserFacingError should always be serializable")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{to_exit_code, wrap_text};
+    use crate::{ErrorCause, UserFacingError};
+
+    #[test]
+    fn to_exit_code_resolves_root_first() {
+        let error = UserFacingError {
+            error: ErrorCause::default().summary("root").exit_code(2),
+            related: vec![UserFacingError {
+                error: ErrorCause::default().summary("cause").exit_code(3),
+                related: vec![],
+            }],
+        };
+
+        assert_eq!(to_exit_code(&error), 2);
+    }
+
+    #[test]
+    fn to_exit_code_falls_through_to_related_when_unset() {
+        let error = UserFacingError {
+            error: ErrorCause::default().summary("root"),
+            related: vec![UserFacingError {
+                error: ErrorCause::default().summary("cause").exit_code(3),
+                related: vec![],
+            }],
+        };
+
+        assert_eq!(to_exit_code(&error), 3);
+    }
+
+    #[test]
+    fn to_exit_code_defaults_to_one_when_no_code_is_set() {
+        let error = UserFacingError {
+            error: ErrorCause::default().summary("root"),
+            related: vec![],
+        };
+
+        assert_eq!(to_exit_code(&error), 1);
+    }
+
+    #[test]
+    fn wrap_text_breaks_at_width_on_word_boundaries() {
+        let wrapped = wrap_text("one two three four", 9, "");
+        assert_eq!(wrapped, "one two\nthree\nfour");
+    }
+
+    #[test]
+    fn wrap_text_preserves_explicit_newlines_and_indents_continuations() {
+        let wrapped = wrap_text("one two three\nfour", 9, "  ");
+        assert_eq!(wrapped, "  one two\n  three\n  four");
+    }
+}